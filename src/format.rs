@@ -0,0 +1,123 @@
+//! Renders an [`Address`] into a human-readable postal string, following the
+//! Czech convention and modelled on libaddressinput's per-region format tokens.
+
+use crate::Address;
+
+impl Address {
+    /// Renders the street/number line of the address: "Ulice číslo_domovní/číslo_orientačníznak"
+    /// when the town has a street network, falling back to the part-of-town name otherwise.
+    fn format_street_line(&self) -> String {
+        let place = self.street.as_deref().unwrap_or(&self.town_part);
+
+        match self.orientation_number {
+            Some(orientation_number) => {
+                let sign = self.orientation_number_sign.as_deref().unwrap_or("");
+                format!("{place} {}/{orientation_number}{sign}", self.number)
+            }
+            None => format!("{place} {}", self.number),
+        }
+    }
+
+    /// Renders the address as printable lines, following the Czech postal
+    /// convention: a street/number line, then a "PSČ Obec" line.
+    pub fn format_lines(&self) -> Vec<String> {
+        vec![
+            self.format_street_line(),
+            format!("{:05} {}", self.zip_code, self.town),
+        ]
+    }
+
+    /// Renders the address using a caller-supplied template, in the style of
+    /// libaddressinput's format tokens:
+    ///
+    /// - `%A` — the street/number line (see [`Address::format_lines`])
+    /// - `%C` — the locality (`town`)
+    /// - `%Z` — the postal code (`zip_code`), zero-padded to 5 digits
+    /// - `%O` — the organisation, i.e. the city/Prague part name, if any
+    ///
+    /// Any other `%` escape is copied through verbatim.
+    pub fn format_with(&self, template: &str) -> String {
+        let mut result = String::with_capacity(template.len());
+        let mut chars = template.chars();
+
+        while let Some(c) = chars.next() {
+            if c != '%' {
+                result.push(c);
+                continue;
+            }
+
+            match chars.next() {
+                Some('A') => result.push_str(&self.format_street_line()),
+                Some('C') => result.push_str(&self.town),
+                Some('Z') => result.push_str(&format!("{:05}", self.zip_code)),
+                Some('O') => {
+                    if let Some(part) = self.city_part.as_deref().or(self.prague_part.as_deref()) {
+                        result.push_str(part);
+                    }
+                }
+                Some(other) => {
+                    result.push('%');
+                    result.push(other);
+                }
+                None => result.push('%'),
+            }
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::sample_address;
+
+    fn base_address() -> Address {
+        Address {
+            town: "Golčův Jeníkov".to_string(),
+            town_part: "Golčův Jeníkov".to_string(),
+            street: Some("Nám. T. G. Masaryka".to_string()),
+            number: 110,
+            orientation_number: Some(5),
+            zip_code: 582_82,
+            ..sample_address()
+        }
+    }
+
+    #[test]
+    fn formats_lines_with_street() {
+        let address = base_address();
+        assert_eq!(
+            address.format_lines(),
+            vec![
+                "Nám. T. G. Masaryka 110/5".to_string(),
+                "58282 Golčův Jeníkov".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn falls_back_to_town_part_without_street_network() {
+        let mut address = base_address();
+        address.street_code = None;
+        address.street = None;
+        address.orientation_number = None;
+
+        assert_eq!(
+            address.format_lines(),
+            vec![
+                "Golčův Jeníkov 110".to_string(),
+                "58282 Golčův Jeníkov".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn formats_with_custom_template() {
+        let address = base_address();
+        assert_eq!(
+            address.format_with("%A, %Z %C"),
+            "Nám. T. G. Masaryka 110/5, 58282 Golčův Jeníkov"
+        );
+    }
+}
@@ -0,0 +1,90 @@
+//! GeoJSON export of addresses in WGS84, for use with mapping tools and
+//! OSM-editing workflows.
+
+use serde_json::json;
+
+use crate::Address;
+
+/// Renders `addresses` as a GeoJSON `FeatureCollection` of `Point` features in
+/// WGS84, carrying the most relevant fields for mapping and OSM imports as
+/// properties. Addresses with no coordinates are skipped.
+///
+/// Coordinate accuracy is inherited entirely from [`Address::wgs84`]; see its
+/// doc comment for what that accuracy depends on.
+pub fn to_geojson(addresses: &[Address]) -> serde_json::Value {
+    let features: Vec<_> = addresses
+        .iter()
+        .filter_map(|address| {
+            let (lat, lon) = address.wgs84()?;
+            Some(json!({
+                "type": "Feature",
+                "geometry": {
+                    "type": "Point",
+                    "coordinates": [lon, lat],
+                },
+                "properties": {
+                    "adm_code": address.adm_code,
+                    "town": address.town,
+                    "street": address.street,
+                    "number": address.number,
+                    "orientation_number": address.orientation_number,
+                    "zip_code": address.zip_code,
+                    "valid_since": address.valid_since,
+                },
+            }))
+        })
+        .collect();
+
+    json!({
+        "type": "FeatureCollection",
+        "features": features,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::sample_address;
+
+    fn address_with_coords(x: Option<f32>, y: Option<f32>) -> Address {
+        Address {
+            location_x: x,
+            location_y: y,
+            ..sample_address()
+        }
+    }
+
+    #[test]
+    fn skips_addresses_without_coordinates() {
+        let addresses = vec![address_with_coords(None, None)];
+        let geojson = to_geojson(&addresses);
+        assert_eq!(geojson["features"].as_array().unwrap().len(), 0);
+    }
+
+    #[test]
+    fn renders_a_point_feature() {
+        let addresses = vec![address_with_coords(Some(1_042_438.0), Some(743_278.0))];
+        let geojson = to_geojson(&addresses);
+
+        assert_eq!(geojson["type"], "FeatureCollection");
+        let feature = &geojson["features"][0];
+        assert_eq!(feature["type"], "Feature");
+        assert_eq!(feature["geometry"]["type"], "Point");
+        assert_eq!(feature["properties"]["adm_code"], 1);
+    }
+
+    #[test]
+    fn point_coordinates_match_address_wgs84() {
+        let address = address_with_coords(Some(1_042_438.0), Some(743_278.0));
+        let (lat, lon) = address.wgs84().unwrap();
+        let addresses = vec![address];
+
+        let geojson = to_geojson(&addresses);
+        let coordinates = geojson["features"][0]["geometry"]["coordinates"]
+            .as_array()
+            .unwrap();
+
+        assert_eq!(coordinates[0].as_f64().unwrap(), lon);
+        assert_eq!(coordinates[1].as_f64().unwrap(), lat);
+    }
+}
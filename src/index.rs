@@ -0,0 +1,291 @@
+//! In-memory indexing over a parsed address set, for O(1) lookup by `adm_code`,
+//! grouped access by `town_code`/`zip_code`, and nearest-point queries, instead of
+//! repeatedly scanning the full address list.
+
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap};
+
+use crate::Address;
+
+/// A node of the k-d tree built over `(location_x, location_y)`, storing the index
+/// of the address it represents into `AddressIndex::addresses`.
+struct KdNode {
+    index: usize,
+    left: Option<Box<KdNode>>,
+    right: Option<Box<KdNode>>,
+}
+
+impl KdNode {
+    fn build(points: &mut [(usize, f32, f32)], depth: usize) -> Option<Box<KdNode>> {
+        if points.is_empty() {
+            return None;
+        }
+
+        let axis = depth % 2;
+        points.sort_by(|a, b| {
+            let (ka, kb) = if axis == 0 { (a.1, b.1) } else { (a.2, b.2) };
+            ka.total_cmp(&kb)
+        });
+
+        let mid = points.len() / 2;
+        let (left_points, rest) = points.split_at_mut(mid);
+        let (median, right_points) = rest.split_first_mut().unwrap();
+
+        Some(Box::new(KdNode {
+            index: median.0,
+            left: KdNode::build(left_points, depth + 1),
+            right: KdNode::build(right_points, depth + 1),
+        }))
+    }
+}
+
+/// A candidate neighbour, ordered by squared distance so the nearest-k search can
+/// keep a bounded max-heap of the best candidates seen so far.
+struct Candidate {
+    dist_sq: f64,
+    index: usize,
+}
+
+impl PartialEq for Candidate {
+    fn eq(&self, other: &Self) -> bool {
+        self.dist_sq == other.dist_sq
+    }
+}
+
+impl Eq for Candidate {}
+
+impl PartialOrd for Candidate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Candidate {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.dist_sq.total_cmp(&other.dist_sq)
+    }
+}
+
+fn search_nearest(
+    node: &Option<Box<KdNode>>,
+    addresses: &[Address],
+    target: (f64, f64),
+    depth: usize,
+    k: usize,
+    heap: &mut BinaryHeap<Candidate>,
+) {
+    let Some(node) = node else {
+        return;
+    };
+
+    let address = &addresses[node.index];
+    let point = (
+        address.location_x.unwrap() as f64,
+        address.location_y.unwrap() as f64,
+    );
+    let dist_sq = (point.0 - target.0).powi(2) + (point.1 - target.1).powi(2);
+
+    if heap.len() < k {
+        heap.push(Candidate {
+            dist_sq,
+            index: node.index,
+        });
+    } else if dist_sq < heap.peek().unwrap().dist_sq {
+        heap.pop();
+        heap.push(Candidate {
+            dist_sq,
+            index: node.index,
+        });
+    }
+
+    let axis = depth % 2;
+    let (target_val, point_val) = if axis == 0 {
+        (target.0, point.0)
+    } else {
+        (target.1, point.1)
+    };
+
+    let (near, far) = if target_val < point_val {
+        (&node.left, &node.right)
+    } else {
+        (&node.right, &node.left)
+    };
+    search_nearest(near, addresses, target, depth + 1, k, heap);
+
+    let diff = target_val - point_val;
+    if heap.len() < k || diff * diff < heap.peek().unwrap().dist_sq {
+        search_nearest(far, addresses, target, depth + 1, k, heap);
+    }
+}
+
+/// An indexed view over a parsed address set, supporting O(1) lookup by
+/// `adm_code`, grouped access by `town_code`/`zip_code`, and nearest-neighbour
+/// queries by S-JTSK distance.
+pub struct AddressIndex {
+    addresses: Vec<Address>,
+    by_adm_code: HashMap<u32, usize>,
+    by_town_code: HashMap<u32, Vec<usize>>,
+    by_zip_code: HashMap<u32, Vec<usize>>,
+    kd_root: Option<Box<KdNode>>,
+}
+
+impl AddressIndex {
+    /// Builds an index over `addresses`, consuming them.
+    pub fn new(addresses: Vec<Address>) -> Self {
+        let mut by_adm_code = HashMap::with_capacity(addresses.len());
+        let mut by_town_code: HashMap<u32, Vec<usize>> = HashMap::new();
+        let mut by_zip_code: HashMap<u32, Vec<usize>> = HashMap::new();
+        let mut located_points = Vec::new();
+
+        for (index, address) in addresses.iter().enumerate() {
+            by_adm_code.insert(address.adm_code, index);
+            by_town_code.entry(address.town_code).or_default().push(index);
+            by_zip_code.entry(address.zip_code).or_default().push(index);
+            if let (Some(x), Some(y)) = (address.location_x, address.location_y) {
+                // Skip non-finite coordinates (e.g. from a malformed CSV cell):
+                // they have no meaningful distance to anything and would corrupt
+                // the k-d tree's ordering invariant.
+                if x.is_finite() && y.is_finite() {
+                    located_points.push((index, x, y));
+                }
+            }
+        }
+
+        let kd_root = KdNode::build(&mut located_points, 0);
+
+        AddressIndex {
+            addresses,
+            by_adm_code,
+            by_town_code,
+            by_zip_code,
+            kd_root,
+        }
+    }
+
+    /// Looks up the address with the given `adm_code`, in O(1).
+    pub fn get(&self, adm_code: u32) -> Option<&Address> {
+        self.by_adm_code
+            .get(&adm_code)
+            .map(|&index| &self.addresses[index])
+    }
+
+    /// Returns all addresses belonging to the given `town_code`.
+    pub fn by_town(&self, town_code: u32) -> impl Iterator<Item = &Address> {
+        self.by_town_code
+            .get(&town_code)
+            .into_iter()
+            .flatten()
+            .map(|&index| &self.addresses[index])
+    }
+
+    /// Returns all addresses with the given `zip_code`.
+    pub fn by_zip(&self, zip_code: u32) -> impl Iterator<Item = &Address> {
+        self.by_zip_code
+            .get(&zip_code)
+            .into_iter()
+            .flatten()
+            .map(|&index| &self.addresses[index])
+    }
+
+    /// Returns the `k` addresses with known coordinates nearest to the S-JTSK point
+    /// `(x, y)`, ordered from closest to farthest.
+    pub fn nearest(&self, x: f32, y: f32, k: usize) -> Vec<&Address> {
+        if k == 0 {
+            return Vec::new();
+        }
+
+        let mut heap = BinaryHeap::new();
+        search_nearest(
+            &self.kd_root,
+            &self.addresses,
+            (x as f64, y as f64),
+            0,
+            k,
+            &mut heap,
+        );
+
+        heap.into_sorted_vec()
+            .into_iter()
+            .map(|candidate| &self.addresses[candidate.index])
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{csv_archive_path, sample_address};
+    use std::fs::File;
+
+    fn address(adm_code: u32, town_code: u32, zip_code: u32, x: f32, y: f32) -> Address {
+        Address {
+            adm_code,
+            town_code,
+            zip_code,
+            location_x: Some(x),
+            location_y: Some(y),
+            ..sample_address()
+        }
+    }
+
+    fn sample_index() -> AddressIndex {
+        AddressIndex::new(vec![
+            address(1, 10, 11000, 0.0, 0.0),
+            address(2, 10, 12000, 1.0, 0.0),
+            address(3, 20, 11000, 10.0, 10.0),
+            address(4, 20, 12000, 11.0, 11.0),
+        ])
+    }
+
+    #[test]
+    fn looks_up_by_adm_code() {
+        let index = sample_index();
+        assert_eq!(index.get(3).unwrap().adm_code, 3);
+        assert!(index.get(999).is_none());
+    }
+
+    #[test]
+    fn groups_by_town_code_and_zip_code() {
+        let index = sample_index();
+        let town_10: Vec<_> = index.by_town(10).map(|a| a.adm_code).collect();
+        assert_eq!(town_10, vec![1, 2]);
+
+        let zip_11000: Vec<_> = index.by_zip(11000).map(|a| a.adm_code).collect();
+        assert_eq!(zip_11000, vec![1, 3]);
+    }
+
+    #[test]
+    fn finds_nearest_neighbours() {
+        let index = sample_index();
+        let nearest: Vec<_> = index.nearest(0.3, 0.0, 2).iter().map(|a| a.adm_code).collect();
+        assert_eq!(nearest, vec![1, 2]);
+    }
+
+    #[test]
+    fn ignores_non_finite_coordinates_instead_of_panicking() {
+        let index = AddressIndex::new(vec![
+            address(1, 10, 11000, f32::NAN, 0.0),
+            address(2, 10, 11000, 0.0, f32::INFINITY),
+            address(3, 10, 11000, 1.0, 1.0),
+        ]);
+
+        assert_eq!(index.get(1).unwrap().adm_code, 1);
+
+        let nearest: Vec<_> = index.nearest(0.0, 0.0, 3).iter().map(|a| a.adm_code).collect();
+        assert_eq!(nearest, vec![3]);
+    }
+
+    #[test]
+    fn looks_up_real_dataset_by_adm_code() {
+        let addresses =
+            crate::parse_addresses_from_csv(File::open(csv_archive_path()).unwrap()).unwrap();
+        assert!(addresses.len() > 2_000_000);
+
+        let index = AddressIndex::new(addresses);
+
+        let address = index.get(9382372).unwrap();
+        assert_eq!(address.town, "Golčův Jeníkov");
+        assert_eq!(address.street, Some("Nám. T. G. Masaryka".to_string()));
+        assert_eq!(address.number, 110);
+    }
+}
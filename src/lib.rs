@@ -5,6 +5,17 @@ use encoding_rs_io::DecodeReaderBytesBuilder;
 use serde::Deserialize;
 use thiserror::Error;
 
+mod coords;
+mod format;
+#[cfg(feature = "geojson")]
+mod geojson;
+mod index;
+mod validation;
+#[cfg(feature = "geojson")]
+pub use geojson::to_geojson;
+pub use index::AddressIndex;
+pub use validation::AddressProblem;
+
 mod address_date_format {
     use std::str::FromStr;
 
@@ -92,43 +103,141 @@ pub enum AddressError {
     Csv(#[from] csv::Error),
 }
 
-/// Parses the Czech Republic addresses provided by [RUIAN](https://nahlizenidokn.cuzk.cz/StahniAdresniMistaRUIAN.aspx) in the ZIP format that contains CSV files.
-pub fn parse_addresses_from_csv(reader: impl Read + Seek) -> anyhow::Result<Vec<Address>> {
-    let mut addresses = Vec::new();
-    let mut zip = zip::ZipArchive::new(reader)?;
-    for i in 0..zip.len() {
-        let csv_file = zip.by_index(i)?;
-        let decoder = DecodeReaderBytesBuilder::new()
+/// Lazily iterates over the `Address` rows contained in a RUIAN CSV ZIP, decoding
+/// and parsing one ZIP entry at a time instead of holding the whole dataset in memory.
+pub struct AddressIter<R: Read + Seek> {
+    zip: zip::ZipArchive<R>,
+    next_entry: usize,
+    current: Option<csv::DeserializeRecordsIntoIter<io::Cursor<Vec<u8>>, Address>>,
+}
+
+impl<R: Read + Seek> AddressIter<R> {
+    /// Decodes and opens the next ZIP entry as a CSV reader, returning `false` once
+    /// every entry has been consumed.
+    fn advance_entry(&mut self) -> Result<bool, AddressError> {
+        if self.next_entry >= self.zip.len() {
+            return Ok(false);
+        }
+        let csv_file = self.zip.by_index(self.next_entry)?;
+        self.next_entry += 1;
+
+        let mut decoder = DecodeReaderBytesBuilder::new()
             .encoding(Some(encoding_rs::WINDOWS_1250))
             .build(csv_file);
-        let mut rdr = csv::ReaderBuilder::new()
+        let mut decoded = Vec::new();
+        decoder.read_to_end(&mut decoded)?;
+
+        let rdr = csv::ReaderBuilder::new()
             .delimiter(b';')
             .has_headers(true)
-            .from_reader(decoder);
-        addresses.extend(rdr.deserialize().collect::<Result<Vec<_>, _>>()?);
+            .from_reader(io::Cursor::new(decoded));
+        self.current = Some(rdr.into_deserialize());
+        Ok(true)
     }
-    Ok(addresses)
 }
 
+impl<R: Read + Seek> Iterator for AddressIter<R> {
+    type Item = Result<Address, AddressError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(current) = &mut self.current {
+                match current.next() {
+                    Some(Ok(address)) => return Some(Ok(address)),
+                    Some(Err(err)) => return Some(Err(err.into())),
+                    None => self.current = None,
+                }
+            }
+
+            match self.advance_entry() {
+                Ok(true) => continue,
+                Ok(false) => return None,
+                Err(err) => return Some(Err(err)),
+            }
+        }
+    }
+}
+
+/// Returns a streaming iterator over the `Address` rows of a RUIAN CSV ZIP, suitable
+/// for filtering or loading into a database without collecting everything into a `Vec` first.
+pub fn addresses_iter<R: Read + Seek>(reader: R) -> Result<AddressIter<R>, AddressError> {
+    let zip = zip::ZipArchive::new(reader)?;
+    Ok(AddressIter {
+        zip,
+        next_entry: 0,
+        current: None,
+    })
+}
+
+/// Parses the Czech Republic addresses provided by [RUIAN](https://nahlizenidokn.cuzk.cz/StahniAdresniMistaRUIAN.aspx) in the ZIP format that contains CSV files.
+pub fn parse_addresses_from_csv(reader: impl Read + Seek) -> anyhow::Result<Vec<Address>> {
+    Ok(addresses_iter(reader)?.collect::<Result<Vec<_>, _>>()?)
+}
+
+/// Shared fixtures for the unit tests of this crate's modules, so each module
+/// doesn't have to re-derive its own `Address` sample or CSV-download boilerplate.
 #[cfg(test)]
-mod tests {
-    use super::*;
+pub(crate) mod test_support {
     use std::{fs::File, path::PathBuf, str::FromStr};
 
-    #[test]
-    fn parse_addresses() {
-        let csv_archive_path = PathBuf::from_str("20240531_OB_ADR_csv.zip").unwrap();
-        if !csv_archive_path.exists() {
+    use chrono::{DateTime, Utc};
+
+    use crate::Address;
+
+    /// An `Address` with representative values (a streeted Prague address). Tests
+    /// that need different field values should override them via struct update
+    /// syntax, e.g. `Address { zip_code: 123, ..sample_address() }`.
+    pub(crate) fn sample_address() -> Address {
+        Address {
+            adm_code: 1,
+            town_code: 1,
+            town: "Praha".to_string(),
+            city_part_code: None,
+            city_part: None,
+            prague_part_code: None,
+            prague_part: None,
+            town_part_code: 1,
+            town_part: "Staré Město".to_string(),
+            street_code: Some(1),
+            street: Some("Na Příkopě".to_string()),
+            object_type: "čp".to_string(),
+            number: 1,
+            orientation_number: Some(1),
+            orientation_number_sign: None,
+            zip_code: 11000,
+            location_x: None,
+            location_y: None,
+            valid_since: DateTime::<Utc>::default(),
+        }
+    }
+
+    /// Downloads (and caches on disk) the full RUIAN CSV ZIP used by the
+    /// real-dataset tests, so they exercise the 2M+ row dataset instead of a toy
+    /// fixture.
+    pub(crate) fn csv_archive_path() -> PathBuf {
+        let path = PathBuf::from_str("20240531_OB_ADR_csv.zip").unwrap();
+        if !path.exists() {
             let mut response = reqwest::blocking::get(
                 "https://vdp.cuzk.cz/vymenny_format/csv/20240531_OB_ADR_csv.zip",
             )
             .unwrap();
 
-            let mut file = File::create_new(csv_archive_path.clone()).unwrap();
+            let mut file = File::create_new(path.clone()).unwrap();
             response.copy_to(&mut file).unwrap();
         }
+        path
+    }
+}
 
-        let addresses = parse_addresses_from_csv(File::open(csv_archive_path).unwrap()).unwrap();
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+    use test_support::csv_archive_path;
+
+    #[test]
+    fn parse_addresses() {
+        let addresses = parse_addresses_from_csv(File::open(csv_archive_path()).unwrap()).unwrap();
 
         assert!(addresses.len() > 2_000_000);
 
@@ -137,4 +246,15 @@ mod tests {
         assert_eq!(address.street, Some("Nám. T. G. Masaryka".to_string()));
         assert_eq!(address.number, 110);
     }
+
+    #[test]
+    fn addresses_iter_yields_full_dataset() {
+        let count = addresses_iter(File::open(csv_archive_path()).unwrap())
+            .unwrap()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap()
+            .len();
+
+        assert!(count > 2_000_000);
+    }
 }
@@ -0,0 +1,225 @@
+use thiserror::Error;
+
+use crate::Address;
+
+/// A semantic or format problem found in an [`Address`] by [`Address::validate`].
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum AddressProblem {
+    /// A field that is required for this kind of address is missing.
+    #[error("missing required field `{0}`")]
+    MissingRequiredField(&'static str),
+    /// A field is present but does not match the expected format.
+    #[error("invalid format in `{0}`")]
+    InvalidFormat(&'static str),
+    /// Two related fields disagree with each other.
+    #[error("`{0}` and `{1}` do not match")]
+    MismatchingValue(&'static str, &'static str),
+}
+
+/// Returns whether `sign` is a single letter, as required for `orientation_number_sign`
+/// (znak čísla orientačního).
+fn is_single_letter(sign: &str) -> bool {
+    let mut chars = sign.chars();
+    matches!((chars.next(), chars.next()), (Some(c), None) if c.is_alphabetic())
+}
+
+impl Address {
+    /// Validates the semantic and format rules of the Czech RUIAN address system,
+    /// beyond what plain CSV deserialization already enforces.
+    ///
+    /// Note that the `city_part`/`prague_part` and `street`/`street_code` checks
+    /// below only verify internal consistency of each code/name pair: this crate
+    /// doesn't carry the real list of statutory cities or towns with a street
+    /// network, so it cannot reject e.g. a `city_part` wrongly set on a
+    /// non-statutory-city row.
+    ///
+    /// Returns `Ok(())` when no problems were found, or the full list of
+    /// [`AddressProblem`]s otherwise, so callers such as OSM importers can reject
+    /// a row before uploading it.
+    pub fn validate(&self) -> Result<(), Vec<AddressProblem>> {
+        let mut problems = Vec::new();
+
+        if self.town.trim().is_empty() {
+            problems.push(AddressProblem::MissingRequiredField("town"));
+        }
+
+        if self.town_part.trim().is_empty() {
+            problems.push(AddressProblem::MissingRequiredField("town_part"));
+        }
+
+        if !(10_000..=99_999).contains(&self.zip_code) {
+            problems.push(AddressProblem::InvalidFormat("zip_code"));
+        }
+
+        if let Some(sign) = &self.orientation_number_sign {
+            if !is_single_letter(sign) {
+                problems.push(AddressProblem::InvalidFormat("orientation_number_sign"));
+            }
+            if self.orientation_number.is_none() {
+                problems.push(AddressProblem::MismatchingValue(
+                    "orientation_number_sign",
+                    "orientation_number",
+                ));
+            }
+        }
+
+        if matches!(self.orientation_number, Some(0)) {
+            problems.push(AddressProblem::InvalidFormat("orientation_number"));
+        }
+
+        // Internal consistency only: see the note on `validate` above.
+        if self.city_part_code.is_some() != self.city_part.is_some() {
+            problems.push(AddressProblem::MismatchingValue(
+                "city_part_code",
+                "city_part",
+            ));
+        }
+
+        // Internal consistency only: see the note on `validate` above.
+        if self.prague_part_code.is_some() != self.prague_part.is_some() {
+            problems.push(AddressProblem::MismatchingValue(
+                "prague_part_code",
+                "prague_part",
+            ));
+        }
+
+        if self.city_part.is_some() && self.prague_part.is_some() {
+            problems.push(AddressProblem::MismatchingValue("city_part", "prague_part"));
+        }
+
+        // Internal consistency only: see the note on `validate` above.
+        if self.street_code.is_some() != self.street.is_some() {
+            problems.push(AddressProblem::MismatchingValue("street_code", "street"));
+        }
+
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(problems)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::sample_address;
+
+    #[test]
+    fn valid_address_has_no_problems() {
+        assert_eq!(sample_address().validate(), Ok(()));
+    }
+
+    #[test]
+    fn rejects_multi_letter_orientation_sign() {
+        let address = Address {
+            orientation_number_sign: Some("ab".to_string()),
+            ..sample_address()
+        };
+        assert_eq!(
+            address.validate(),
+            Err(vec![AddressProblem::InvalidFormat(
+                "orientation_number_sign"
+            )])
+        );
+    }
+
+    #[test]
+    fn rejects_short_zip_code() {
+        let address = Address {
+            zip_code: 123,
+            ..sample_address()
+        };
+        assert_eq!(
+            address.validate(),
+            Err(vec![AddressProblem::InvalidFormat("zip_code")])
+        );
+    }
+
+    #[test]
+    fn rejects_mismatched_street_fields() {
+        let address = Address {
+            street: None,
+            ..sample_address()
+        };
+        assert_eq!(
+            address.validate(),
+            Err(vec![AddressProblem::MismatchingValue(
+                "street_code",
+                "street"
+            )])
+        );
+    }
+
+    #[test]
+    fn rejects_empty_town() {
+        let address = Address {
+            town: String::new(),
+            ..sample_address()
+        };
+        assert_eq!(
+            address.validate(),
+            Err(vec![AddressProblem::MissingRequiredField("town")])
+        );
+    }
+
+    #[test]
+    fn rejects_zero_orientation_number() {
+        let address = Address {
+            orientation_number: Some(0),
+            ..sample_address()
+        };
+        assert_eq!(
+            address.validate(),
+            Err(vec![AddressProblem::InvalidFormat("orientation_number")])
+        );
+    }
+
+    #[test]
+    fn rejects_orientation_sign_without_orientation_number() {
+        let address = Address {
+            orientation_number: None,
+            orientation_number_sign: Some("a".to_string()),
+            ..sample_address()
+        };
+        assert_eq!(
+            address.validate(),
+            Err(vec![AddressProblem::MismatchingValue(
+                "orientation_number_sign",
+                "orientation_number"
+            )])
+        );
+    }
+
+    #[test]
+    fn rejects_mismatched_city_part_fields() {
+        let address = Address {
+            city_part_code: Some(1),
+            city_part: None,
+            ..sample_address()
+        };
+        assert_eq!(
+            address.validate(),
+            Err(vec![AddressProblem::MismatchingValue(
+                "city_part_code",
+                "city_part"
+            )])
+        );
+    }
+
+    #[test]
+    fn rejects_mismatched_prague_part_fields() {
+        let address = Address {
+            prague_part_code: Some(1),
+            prague_part: None,
+            ..sample_address()
+        };
+        assert_eq!(
+            address.validate(),
+            Err(vec![AddressProblem::MismatchingValue(
+                "prague_part_code",
+                "prague_part"
+            )])
+        );
+    }
+}